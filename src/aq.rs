@@ -0,0 +1,83 @@
+//! Oracle Advanced Queuing (AQ) on top of a pooled connection.
+//!
+//! Requires the `aq` feature, which pulls in the `oracle` crate's `aq_unstable`
+//! feature.
+
+use std::sync::Arc;
+
+use oracle::aq::{MsgProps, Payload, Queue};
+
+use crate::{map_blocking, Error, OracleConnectionManager};
+
+/// An AQ producer/consumer bound to a `bb8::Pool<OracleConnectionManager>`.
+///
+/// Each `enqueue`/`dequeue` call checks out a connection from the pool, opens the
+/// named queue on it, and runs the blocking AQ call on `spawn_blocking`, mapping
+/// the join/`oracle::Error` into this crate's `Error` — the same bridging pattern
+/// as `AsyncOracleConnection`. The `bb8::PooledConnection` guard is held for the
+/// duration of the blocking call so the connection isn't returned to the pool (and
+/// possibly handed to another borrower) while it's still in use on another thread.
+pub struct PooledQueue<T: Payload> {
+    pool: bb8::Pool<OracleConnectionManager>,
+    queue_name: String,
+    payload_type: T::Schema,
+}
+
+impl<T: Payload + Send + 'static> PooledQueue<T> {
+    /// Creates a queue handle for `queue_name`, using connections from `pool`.
+    pub fn new(pool: bb8::Pool<OracleConnectionManager>, queue_name: impl Into<String>, payload_type: T::Schema) -> Self {
+        PooledQueue {
+            pool,
+            queue_name: queue_name.into(),
+            payload_type,
+        }
+    }
+
+    /// Checks out a connection and enqueues `payload`.
+    pub async fn enqueue(&self, payload: T) -> Result<(), Error>
+    where
+        T::Schema: Clone + Send + 'static,
+    {
+        let guard = self.checkout().await?;
+        let conn = Arc::clone(&*guard);
+        let queue_name = self.queue_name.clone();
+        let payload_type = self.payload_type.clone();
+        let result = tokio::task::spawn_blocking(move || {
+            let queue = Queue::new(&conn, &queue_name, payload_type)?;
+            let mut props = MsgProps::new(&conn)?;
+            props.set_payload(&payload)?;
+            queue.enqueue(&props)
+        })
+        .await;
+        let outcome = map_blocking(result);
+        drop(guard);
+        outcome
+    }
+
+    /// Checks out a connection, dequeues the next message, and returns its payload.
+    pub async fn dequeue(&self) -> Result<T, Error>
+    where
+        T::Schema: Clone + Send + 'static,
+    {
+        let guard = self.checkout().await?;
+        let conn = Arc::clone(&*guard);
+        let queue_name = self.queue_name.clone();
+        let payload_type = self.payload_type.clone();
+        let result = tokio::task::spawn_blocking(move || {
+            let queue = Queue::new(&conn, &queue_name, payload_type)?;
+            let props = queue.dequeue()?;
+            props.payload()
+        })
+        .await;
+        let outcome = map_blocking(result);
+        drop(guard);
+        outcome
+    }
+
+    async fn checkout(&self) -> Result<bb8::PooledConnection<'_, OracleConnectionManager>, Error> {
+        self.pool.get().await.map_err(|e| match e {
+            bb8::RunError::User(err) => err,
+            bb8::RunError::TimedOut => Error::Pool("timed out waiting for a connection".into()),
+        })
+    }
+}
@@ -0,0 +1,129 @@
+//! An async extension trait for running queries against a pooled `oracle::Connection`
+//! without hand-rolling `tokio::task::spawn_blocking`.
+
+use std::sync::Arc;
+
+use async_trait::async_trait;
+use oracle::sql_type::ToSql;
+use oracle::{Connection, Row, RowValue};
+
+use crate::{map_blocking, Error};
+
+/// A boxed bind parameter, owned so it can cross into the `spawn_blocking` task.
+pub type SqlParam = Box<dyn ToSql + Send + Sync>;
+
+/// Async counterparts of the blocking `oracle::Connection` query methods.
+///
+/// Implemented for `Arc<oracle::Connection>` (the `Connection` type of
+/// `OracleConnectionManager`), so a connection checked out of the pool can be used
+/// directly from async code. Each method clones the `Arc`, runs the matching blocking
+/// `oracle::Connection` call on `spawn_blocking`, and maps the join/`oracle::Error`
+/// into this crate's `Error`.
+#[async_trait]
+pub trait AsyncOracleConnection {
+    /// Executes `sql` with the given bind parameters; the resulting statement is dropped.
+    async fn execute(&self, sql: &str, params: Vec<SqlParam>) -> Result<(), Error>;
+
+    /// Executes `sql` and collects all result rows.
+    async fn query(&self, sql: &str, params: Vec<SqlParam>) -> Result<Vec<Row>, Error>;
+
+    /// Executes `sql` and returns the single resulting row.
+    async fn query_row(&self, sql: &str, params: Vec<SqlParam>) -> Result<Row, Error>;
+
+    /// Executes `sql` and collects all result rows, decoded as `T`.
+    async fn query_as<T>(&self, sql: &str, params: Vec<SqlParam>) -> Result<Vec<T>, Error>
+    where
+        T: RowValue + Send + 'static;
+
+    /// Executes `sql` once per row of `batch_rows`, using the row-level batch
+    /// execution facility of the driver.
+    async fn batch(&self, sql: &str, batch_rows: Vec<Vec<SqlParam>>) -> Result<(), Error>;
+
+    /// Commits the current transaction.
+    async fn commit(&self) -> Result<(), Error>;
+
+    /// Rolls back the current transaction.
+    async fn rollback(&self) -> Result<(), Error>;
+}
+
+#[async_trait]
+impl AsyncOracleConnection for Arc<Connection> {
+    async fn execute(&self, sql: &str, params: Vec<SqlParam>) -> Result<(), Error> {
+        let conn = Arc::clone(self);
+        let sql = sql.to_owned();
+        let result = tokio::task::spawn_blocking(move || {
+            let refs: Vec<&dyn ToSql> = params.iter().map(|p| p.as_ref()).collect();
+            conn.execute(&sql, &refs).map(|_| ())
+        })
+        .await;
+        map_blocking(result)
+    }
+
+    async fn query(&self, sql: &str, params: Vec<SqlParam>) -> Result<Vec<Row>, Error> {
+        let conn = Arc::clone(self);
+        let sql = sql.to_owned();
+        let result = tokio::task::spawn_blocking(move || {
+            let refs: Vec<&dyn ToSql> = params.iter().map(|p| p.as_ref()).collect();
+            let rows = conn.query(&sql, &refs)?;
+            rows.collect::<oracle::Result<Vec<Row>>>()
+        })
+        .await;
+        map_blocking(result)
+    }
+
+    async fn query_row(&self, sql: &str, params: Vec<SqlParam>) -> Result<Row, Error> {
+        let conn = Arc::clone(self);
+        let sql = sql.to_owned();
+        let result = tokio::task::spawn_blocking(move || {
+            let refs: Vec<&dyn ToSql> = params.iter().map(|p| p.as_ref()).collect();
+            conn.query_row(&sql, &refs)
+        })
+        .await;
+        map_blocking(result)
+    }
+
+    async fn query_as<T>(&self, sql: &str, params: Vec<SqlParam>) -> Result<Vec<T>, Error>
+    where
+        T: RowValue + Send + 'static,
+    {
+        let conn = Arc::clone(self);
+        let sql = sql.to_owned();
+        let result = tokio::task::spawn_blocking(move || {
+            let refs: Vec<&dyn ToSql> = params.iter().map(|p| p.as_ref()).collect();
+            let rows = conn.query_as::<T>(&sql, &refs)?;
+            rows.collect::<oracle::Result<Vec<T>>>()
+        })
+        .await;
+        map_blocking(result)
+    }
+
+    async fn batch(&self, sql: &str, batch_rows: Vec<Vec<SqlParam>>) -> Result<(), Error> {
+        if batch_rows.is_empty() {
+            return Ok(());
+        }
+        let conn = Arc::clone(self);
+        let sql = sql.to_owned();
+        let result = tokio::task::spawn_blocking(move || {
+            let mut batch = conn.batch(&sql, batch_rows.len())?;
+            for row in &batch_rows {
+                let refs: Vec<&dyn ToSql> = row.iter().map(|p| p.as_ref()).collect();
+                batch.append_row(&refs)?;
+            }
+            batch.execute()
+        })
+        .await;
+        map_blocking(result)
+    }
+
+    async fn commit(&self) -> Result<(), Error> {
+        let conn = Arc::clone(self);
+        let result = tokio::task::spawn_blocking(move || conn.commit()).await;
+        map_blocking(result)
+    }
+
+    async fn rollback(&self) -> Result<(), Error> {
+        let conn = Arc::clone(self);
+        let result = tokio::task::spawn_blocking(move || conn.rollback()).await;
+        map_blocking(result)
+    }
+}
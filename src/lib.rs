@@ -16,6 +16,14 @@ use async_trait::async_trait;
 pub use bb8;
 pub use oracle;
 
+mod async_ext;
+pub use async_ext::{AsyncOracleConnection, SqlParam};
+
+#[cfg(feature = "aq")]
+mod aq;
+#[cfg(feature = "aq")]
+pub use aq::PooledQueue;
+
 /// A `bb8::ManageConnection` for `oracle::Connection`s.
 ///
 /// # Example
@@ -38,11 +46,25 @@ pub use oracle;
 ///     });
 /// }
 /// ```
-#[derive(Debug)]
 pub struct OracleConnectionManager {
     connector: oracle::Connector,
+    init: Option<Arc<InitFn>>,
+    reset_session: bool,
 }
 
+impl fmt::Debug for OracleConnectionManager {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("OracleConnectionManager")
+            .field("connector", &self.connector)
+            .field("init", &self.init.as_ref().map(|_| "Fn(&Connection) -> Result<()>"))
+            .field("reset_session", &self.reset_session)
+            .finish()
+    }
+}
+
+/// The type of a connection customizer registered via `with_init`.
+type InitFn = dyn Fn(&oracle::Connection) -> oracle::Result<()> + Send + Sync;
+
 impl OracleConnectionManager {
     /// Initialise the connection manager with the data needed to create new connections.
     /// Refer to the documentation of `oracle::Connection` for further details on the parameters.
@@ -56,6 +78,8 @@ impl OracleConnectionManager {
         let connector = oracle::Connector::new(username, password, connect_string);
         OracleConnectionManager {
             connector,
+            init: None,
+            reset_session: false,
         }
     }
 
@@ -73,7 +97,97 @@ impl OracleConnectionManager {
     /// let manager = OracleConnectionManager::from_connector(connector);
     /// ```
     pub fn from_connector(connector: oracle::Connector) -> OracleConnectionManager {
-        OracleConnectionManager { connector }
+        OracleConnectionManager {
+            connector,
+            init: None,
+            reset_session: false,
+        }
+    }
+
+    /// Registers a customizer that runs once on every freshly created connection,
+    /// before it is handed out by `connect()`.
+    ///
+    /// This is the place to apply session-wide settings that pooled Oracle sessions
+    /// need consistently, such as `ALTER SESSION SET NLS_DATE_FORMAT=...`,
+    /// `CURRENT_SCHEMA`, or time-zone setup. If the closure returns an error, the
+    /// connection is rejected and the error surfaces as `Error::Database`.
+    ///
+    /// # Example
+    /// ```
+    /// # use bb8_oracle::OracleConnectionManager;
+    /// let manager = OracleConnectionManager::new("user", "password", "localhost")
+    ///     .with_init(|conn| conn.execute("ALTER SESSION SET CURRENT_SCHEMA = APP", &[]).map(|_| ()));
+    /// ```
+    pub fn with_init<F>(mut self, init: F) -> Self
+    where
+        F: Fn(&oracle::Connection) -> oracle::Result<()> + Send + Sync + 'static,
+    {
+        self.init = Some(Arc::new(init));
+        self
+    }
+
+    /// Sets the per-connection statement cache size, forwarded to `oracle::Connector`.
+    ///
+    /// Applies to the inner connector whether the manager was built with `new` or
+    /// `from_connector`.
+    pub fn stmt_cache_size(mut self, size: u32) -> Self {
+        self.connector.stmt_cache_size(size);
+        self
+    }
+
+    /// Sets the default number of rows prefetched per round-trip, forwarded to
+    /// `oracle::Connector`.
+    ///
+    /// Applies to the inner connector whether the manager was built with `new` or
+    /// `from_connector`.
+    pub fn prefetch_rows(mut self, rows: u32) -> Self {
+        self.connector.prefetch_rows(rows);
+        self
+    }
+
+    /// Gives mutable access to the inner `oracle::Connector`, as an escape hatch for
+    /// driver-level knobs that don't have a dedicated builder method on this type.
+    pub fn connector_mut(&mut self) -> &mut oracle::Connector {
+        &mut self.connector
+    }
+
+    /// Enables rolling back any uncommitted transaction before a connection is
+    /// handed out of the pool, so an open transaction left by one borrower isn't
+    /// handed to the next one. Only the transaction is rolled back; other session
+    /// state, such as `ALTER SESSION` settings, is not reset.
+    ///
+    /// When enabled, `is_valid` calls `rollback()` before `ping()`; a failure there
+    /// surfaces as `Error::Database` and the connection is treated as broken rather
+    /// than handed out.
+    pub fn reset_session(mut self, reset: bool) -> Self {
+        self.reset_session = reset;
+        self
+    }
+
+    /// Sets the Database Resident Connection Pooling (DRCP) connection class,
+    /// forwarded to `oracle::Connector`.
+    ///
+    /// DRCP lets many application-side pooled connections (this `bb8::Pool`,
+    /// potentially spread across several processes) share a smaller server-side
+    /// pool keyed by connection class. The connect string must request a pooled
+    /// server, e.g. `//host/service:pooled`. `bb8`'s own `max_size` still bounds how
+    /// many connections *this* pool holds; it does not change the size of the
+    /// server-side DRCP pool, which is configured independently in the database.
+    pub fn connection_class<S: Into<String>>(mut self, connection_class: S) -> Self {
+        self.connector.connection_class(connection_class);
+        self
+    }
+
+    /// Sets the session purity (`NEW` or `SELF`) requested for each connection,
+    /// forwarded to `oracle::Connector`.
+    ///
+    /// Relevant together with `connection_class` when fronting a DRCP-enabled
+    /// service: `Purity::New` always gets a fresh session from the pooled server,
+    /// while `Purity::Self_` lets the session be reused across check-outs of the
+    /// same connection class.
+    pub fn purity(mut self, purity: oracle::Purity) -> Self {
+        self.connector.purity(purity);
+        self
     }
 }
 
@@ -85,18 +199,33 @@ pub enum Error {
 
     /// An error that occurred because a pool operation panicked.
     Panic(tokio::task::JoinError),
+
+    /// An error that occurred while checking out a connection from the pool.
+    Pool(String),
 }
 impl fmt::Display for Error {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         match self {
             Self::Database(e) => write!(f, "database error: {}", e),
             Self::Panic(e) => write!(f, "operation panicked: {}", e),
+            Self::Pool(e) => write!(f, "pool error: {}", e),
         }
     }
 }
 impl std::error::Error for Error {
 }
 
+/// Maps the result of a `tokio::task::spawn_blocking` call running an
+/// `oracle::Result`-returning closure into this crate's `Error`, collapsing the
+/// `Ok(Ok(_))` / `Ok(Err(_))` / `Err(_)` join-result shape used by every blocking
+/// call in this crate.
+pub(crate) fn map_blocking<T>(result: Result<oracle::Result<T>, tokio::task::JoinError>) -> Result<T, Error> {
+    match result {
+        Ok(Ok(v)) => Ok(v),
+        Ok(Err(e)) => Err(Error::Database(e)),
+        Err(e) => Err(Error::Panic(e)),
+    }
+}
 
 #[async_trait]
 impl bb8::ManageConnection for OracleConnectionManager {
@@ -105,26 +234,27 @@ impl bb8::ManageConnection for OracleConnectionManager {
 
     async fn connect(&self) -> Result<Self::Connection, Self::Error> {
         let connector_clone = self.connector.clone();
+        let init = self.init.clone();
         let result = tokio::task::spawn_blocking(move || {
-            connector_clone.connect()
+            let conn = connector_clone.connect()?;
+            if let Some(init) = init {
+                init(&conn)?;
+            }
+            Ok(conn)
         }).await;
-        match result {
-            Ok(Ok(c)) => Ok(Arc::new(c)),
-            Ok(Err(e)) => Err(Error::Database(e)),
-            Err(e) => Err(Error::Panic(e)),
-        }
+        map_blocking(result).map(Arc::new)
     }
 
     async fn is_valid(&self, conn: &mut Self::Connection) -> Result<(), Self::Error> {
         let conn_clone = Arc::clone(&conn);
+        let reset_session = self.reset_session;
         let result = tokio::task::spawn_blocking(move || {
+            if reset_session {
+                conn_clone.rollback()?;
+            }
             conn_clone.ping()
         }).await;
-        match result {
-            Ok(Ok(())) => Ok(()),
-            Ok(Err(e)) => Err(Error::Database(e)),
-            Err(e) => Err(Error::Panic(e)),
-        }
+        map_blocking(result)
     }
 
     fn has_broken(&self, conn: &mut Self::Connection) -> bool {